@@ -0,0 +1,250 @@
+use csv;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use roaring::RoaringBitmap;
+use std::error::Error;
+use plotters::prelude::*;
+
+const K: usize = 5;
+
+struct BatchSearchResult {
+    array_length: usize,
+    query_count: u32,
+    bitmap_bytes: u64,
+    candidates_before_truncate: u32,
+    k: usize,
+    // Top-k indices for the batch's last query.
+    sample_nearest_indices: Vec<u32>,
+}
+
+struct BatchSearchResults {
+    results: Vec<BatchSearchResult>,
+}
+
+pub fn run(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let num_arrays: Vec<Vec<u32>> = get_num_arrays(&mut rng);
+    let mut search_results = BatchSearchResults{results: Vec::with_capacity(num_arrays.len())};
+
+    for num_array in num_arrays {
+        let query_count: u32 = rng.gen_range(3..10);
+        let query_targets: Vec<u32> = (0..query_count)
+            .map(|_| num_array[rng.gen_range(0..num_array.len())])
+            .collect();
+
+        search_results.results.push(batch_knearest(&num_array, &query_targets, K));
+    }
+
+    match write_batch_results(seed, &search_results) {
+        Ok(_) => println!("Batch search results successfully written."),
+        Err(e) => println!("Failed to write batch search results:\n{}", e),
+    }
+
+    match draw_batch_result_graph(&search_results) {
+        Ok(_) => println!("Batch search graph successfully drawn"),
+        Err(e) => println!("Failed to draw batch search graph:\n{}", e),
+    }
+}
+
+// Indices within `k` slots of where `target_num` would sort into `num_array`.
+fn candidate_window(num_array: &[u32], target_num: u32, k: usize) -> std::ops::Range<u32> {
+    let len = num_array.len();
+    let insertion_point = num_array.partition_point(|&value| value < target_num);
+    let radius = k.max(1);
+
+    let start = insertion_point.saturating_sub(radius);
+    let end = (insertion_point + radius).min(len);
+
+    start as u32..end as u32
+}
+
+// Runs a batch of k-nearest queries, encoding each query's candidate set into a `RoaringBitmap`.
+fn batch_knearest(num_array: &[u32], query_targets: &[u32], k: usize) -> BatchSearchResult {
+    let mut candidates_before_truncate: u32 = 0;
+    let mut bitmap_bytes: u64 = 0;
+    let mut sample_nearest_indices: Vec<u32> = Vec::new();
+
+    for &target_num in query_targets {
+        let mut candidates = RoaringBitmap::new();
+        for index in candidate_window(num_array, target_num, k) {
+            candidates.insert(index);
+        }
+
+        candidates_before_truncate += candidates.len() as u32;
+        bitmap_bytes += candidates.serialized_size() as u64;
+
+        let mut by_distance: Vec<(u32, u32)> = candidates
+            .iter()
+            .map(|index| {
+                let distance = (num_array[index as usize] as i64 - target_num as i64).unsigned_abs() as u32;
+                (distance, index)
+            })
+            .collect();
+        by_distance.sort_unstable_by_key(|&(distance, _)| distance);
+        by_distance.truncate(k);
+
+        sample_nearest_indices = by_distance.into_iter().map(|(_, index)| index).collect();
+    }
+
+    BatchSearchResult{
+        array_length: num_array.len(),
+        query_count: query_targets.len() as u32,
+        bitmap_bytes,
+        candidates_before_truncate,
+        k,
+        sample_nearest_indices,
+    }
+}
+
+fn get_num_arrays(rng: &mut StdRng) -> Vec<Vec<u32>> {
+    crate::gen_num_arrays(rng, 200, 2, 500)
+}
+
+fn write_batch_results(seed: u64, search_results: &BatchSearchResults) -> Result<(), Box<dyn Error>> {
+    let mut batch_res_writer = csv::WriterBuilder::new().flexible(true).from_path("batch_search_results.csv")?;
+
+    batch_res_writer.write_record(&[format!("# arrays=200 seed={}", seed)])?;
+    batch_res_writer.write_record(&["Array length", "Query count", "K", "Candidates before truncate", "Bitmap bytes", "Sample nearest indices"])?;
+
+    for result in &search_results.results {
+        let sample_nearest_indices = result.sample_nearest_indices.iter().map(u32::to_string).collect::<Vec<_>>().join(";");
+
+        batch_res_writer.write_record(&[
+            result.array_length.to_string(),
+            result.query_count.to_string(),
+            result.k.to_string(),
+            result.candidates_before_truncate.to_string(),
+            result.bitmap_bytes.to_string(),
+            sample_nearest_indices,
+        ])?;
+    }
+
+    Ok(())
+}
+
+fn draw_batch_result_graph(search_results: &BatchSearchResults) -> Result<(), Box<dyn Error>> {
+    let root = SVGBackend::new("batch_search_results.svg", (1920, 720)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let panels = root.split_evenly((1, 2));
+
+    draw_bitmap_bytes_chart(&panels[0], search_results)?;
+    draw_candidates_chart(&panels[1], search_results)?;
+
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", "batch_search_results.svg");
+
+    Ok(())
+}
+
+fn draw_bitmap_bytes_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &BatchSearchResults) -> Result<(), Box<dyn Error>> {
+    let max_bytes = search_results.results.iter().map(|r| r.bitmap_bytes).max().unwrap_or(1);
+
+    let mut chart = ChartBuilder::on(area)
+        .caption("Batch k-nearest bitmap size", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (2u32..500u32).log_scale(),
+            (0u64..max_bytes + 1).log_scale(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Array length")
+        .y_desc("Bitmap bytes")
+        .draw()?;
+
+    let color = Palette99::pick(5).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.results.iter().map(
+                |&BatchSearchResult { array_length, bitmap_bytes, .. }| (array_length as u32, bitmap_bytes),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Roaring bitmap bytes")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidate_window_clamps_at_the_low_end() {
+        let window = candidate_window(&[10, 20, 30, 40, 50], 5, 2);
+        assert_eq!(window, 0..2);
+    }
+
+    #[test]
+    fn candidate_window_clamps_at_the_high_end() {
+        let window = candidate_window(&[10, 20, 30, 40, 50], 100, 2);
+        assert_eq!(window, 3..5);
+    }
+
+    #[test]
+    fn candidate_window_covers_the_whole_array_when_k_exceeds_it() {
+        let window = candidate_window(&[10, 20, 30], 20, 10);
+        assert_eq!(window, 0..3);
+    }
+
+    #[test]
+    fn batch_knearest_truncates_to_k_and_sorts_by_distance() {
+        let num_array: Vec<u32> = (0..100).collect();
+        let result = batch_knearest(&num_array, &[50], 3);
+
+        assert_eq!(result.sample_nearest_indices.len(), 3);
+        assert_eq!(result.sample_nearest_indices[0], 50);
+
+        let mut rest = result.sample_nearest_indices[1..].to_vec();
+        rest.sort_unstable();
+        assert_eq!(rest, vec![49, 51]);
+    }
+}
+
+fn draw_candidates_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &BatchSearchResults) -> Result<(), Box<dyn Error>> {
+    let max_candidates = search_results.results.iter().map(|r| r.candidates_before_truncate).max().unwrap_or(1);
+
+    let mut chart = ChartBuilder::on(area)
+        .caption("Batch k-nearest candidates before truncation", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (2u32..500u32).log_scale(),
+            (0u32..max_candidates + 1).log_scale(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Array length")
+        .y_desc("Candidates before truncate")
+        .draw()?;
+
+    let color = Palette99::pick(6).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.results.iter().map(
+                |&BatchSearchResult { array_length, candidates_before_truncate, .. }| (array_length as u32, candidates_before_truncate),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Candidates before truncate")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
@@ -1,35 +1,156 @@
 use csv;
+use rand::rngs::StdRng;
 use rand::Rng;
+use rand::SeedableRng;
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::error::Error;
 use plotters::prelude::*;
+use plotters::series::DashedLineSeries;
 use std::fs::File;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Instant;
+
+mod batch_search;
+mod text_search;
+
+struct BenchmarkConfig {
+    array_count: usize,
+    min_size: u32,
+    max_size: u32,
+    trials: u32,
+    seed: u64,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        BenchmarkConfig{array_count: 1000, min_size: 2, max_size: 500, trials: 1, seed: 42}
+    }
+}
+
+// Parses `--arrays`, `--min-size`, `--max-size`, `--trials` and `--seed`.
+fn parse_args() -> BenchmarkConfig {
+    let mut config = BenchmarkConfig::default();
+    let args: Vec<String> = std::env::args().collect();
+
+    let mut i = 1;
+    while i < args.len() {
+        let flag = args[i].as_str();
+        i += 1;
+        let value = args.get(i).unwrap_or_else(|| panic!("{} expects a value", flag));
+
+        match flag {
+            "--arrays" => config.array_count = value.parse().expect("--arrays expects a number"),
+            "--min-size" => config.min_size = value.parse().expect("--min-size expects a number"),
+            "--max-size" => config.max_size = value.parse().expect("--max-size expects a number"),
+            "--trials" => config.trials = value.parse().expect("--trials expects a number"),
+            "--seed" => config.seed = value.parse().expect("--seed expects a number"),
+            other => panic!("Unrecognised argument: {}", other),
+        }
+        i += 1;
+    }
+
+    validate_config(&config);
+    config
+}
+
+fn validate_config(config: &BenchmarkConfig) {
+    if config.min_size < 2 {
+        panic!("--min-size must be at least 2, got {}", config.min_size);
+    }
+    if config.min_size >= config.max_size {
+        panic!("--min-size ({}) must be less than --max-size ({})", config.min_size, config.max_size);
+    }
+}
+
+static ALLOCATED: AtomicI64 = AtomicI64::new(0);
+static RESIDENT: AtomicI64 = AtomicI64::new(0);
+static MAX_RESIDENT: AtomicI64 = AtomicI64::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let size = layout.size() as i64;
+            ALLOCATED.fetch_add(size, Ordering::Relaxed);
+            let resident = RESIDENT.fetch_add(size, Ordering::Relaxed) + size;
+            MAX_RESIDENT.fetch_max(resident, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        RESIDENT.fetch_sub(layout.size() as i64, Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn reset_allocator_stats() {
+    ALLOCATED.store(0, Ordering::Relaxed);
+    MAX_RESIDENT.store(RESIDENT.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+struct AllocatorSnapshot {
+    allocated: i64,
+    resident: i64,
+    max_resident: i64,
+}
+
+fn snapshot_allocator_stats() -> AllocatorSnapshot {
+    AllocatorSnapshot {
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+        resident: RESIDENT.load(Ordering::Relaxed),
+        max_resident: MAX_RESIDENT.load(Ordering::Relaxed),
+    }
+}
 
 struct SearchResult {
     target_index: i32,
     comparison_count: u32,
+    vector_op_count: u32,
+    array_length: usize,
+    peak_bytes: i64,
+    elapsed_ns: u128,
+}
+
+struct AggregatedResult {
     array_length: usize,
+    mean_comparison_count: f64,
+    mean_vector_op_count: f64,
+    mean_peak_bytes: f64,
+    mean_elapsed_ns: f64,
+    min_elapsed_ns: u128,
+    max_elapsed_ns: u128,
 }
 
 struct SearchResults {
-    binary_search_results: Vec<SearchResult>,
-    interp_search_results: Vec<SearchResult>,
-    interp_binary_search_results: Vec<SearchResult>,
+    binary_search_results: Vec<AggregatedResult>,
+    interp_search_results: Vec<AggregatedResult>,
+    interp_binary_search_results: Vec<AggregatedResult>,
+    simd_block_search_results: Vec<AggregatedResult>,
 }
 
 fn main() {
-    let num_arrays: Vec<Vec<u32>> = get_num_arrays();
-    let mut num_generator = rand::thread_rng();
-    let mut search_results = SearchResults{binary_search_results: Vec::with_capacity(1000), interp_search_results: Vec::with_capacity(1000), interp_binary_search_results: Vec::with_capacity(1000)};
+    let config = parse_args();
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    let num_arrays: Vec<Vec<u32>> = get_num_arrays(&config, &mut rng);
+    let mut search_results = SearchResults{binary_search_results: Vec::with_capacity(config.array_count), interp_search_results: Vec::with_capacity(config.array_count), interp_binary_search_results: Vec::with_capacity(config.array_count), simd_block_search_results: Vec::with_capacity(config.array_count)};
 
     for num_array in num_arrays {
-        let target_num: u32 = num_array[(num_generator.gen_range(0..num_array.len() - 1)) as usize];
+        let target_num: u32 = num_array[rng.gen_range(0..num_array.len() - 1)];
 
-        search_results.binary_search_results.push(binary_search(&num_array, target_num));
-        search_results.interp_search_results.push(interpolation_search(&num_array, target_num));
-        search_results.interp_binary_search_results.push(interpolated_binary_search(&num_array, target_num));
+        search_results.binary_search_results.push(run_trials(config.trials, || measure_search(|| binary_search(&num_array, target_num))));
+        search_results.interp_search_results.push(run_trials(config.trials, || measure_search(|| interpolation_search(&num_array, target_num))));
+        search_results.interp_binary_search_results.push(run_trials(config.trials, || measure_search(|| interpolated_binary_search(&num_array, target_num))));
+        search_results.simd_block_search_results.push(run_trials(config.trials, || measure_search(|| simd_block_search(&num_array, target_num))));
     }
 
-    match write_search_results(&search_results) {
+    match write_search_results(&config, &search_results) {
         Ok(_) => println!("Search results successfully written."),
         Err(e) => println!("Failed to write search results:\n{}", e),
     }
@@ -38,13 +159,68 @@ fn main() {
         Ok(_) => println!("Graph successfully drawn"),
         Err(e) => println!("Failed to draw search result graph:\n{}", e),
     }
+
+    text_search::run(config.seed);
+    batch_search::run(config.seed);
+}
+
+fn run_trials<F>(trials: u32, mut f: F) -> AggregatedResult
+where
+    F: FnMut() -> SearchResult,
+{
+    let samples: Vec<SearchResult> = (0..trials.max(1)).map(|_| f()).collect();
+    aggregate_trials(&samples)
+}
+
+fn aggregate_trials(samples: &[SearchResult]) -> AggregatedResult {
+    let trial_count = samples.len() as f64;
+
+    AggregatedResult {
+        array_length: samples[0].array_length,
+        mean_comparison_count: samples.iter().map(|s| s.comparison_count as f64).sum::<f64>() / trial_count,
+        mean_vector_op_count: samples.iter().map(|s| s.vector_op_count as f64).sum::<f64>() / trial_count,
+        mean_peak_bytes: samples.iter().map(|s| s.peak_bytes as f64).sum::<f64>() / trial_count,
+        mean_elapsed_ns: samples.iter().map(|s| s.elapsed_ns as f64).sum::<f64>() / trial_count,
+        min_elapsed_ns: samples.iter().map(|s| s.elapsed_ns).min().unwrap(),
+        max_elapsed_ns: samples.iter().map(|s| s.elapsed_ns).max().unwrap(),
+    }
 }
 
-fn draw_result_graph(search_results: &SearchResults) -> Result<(), Box<dyn Error>> { 
+fn measure_search<F>(f: F) -> SearchResult
+where
+    F: FnOnce() -> SearchResult,
+{
+    reset_allocator_stats();
+    let baseline_resident = snapshot_allocator_stats().resident;
+
+    let start = Instant::now();
+    let mut result = f();
+    result.elapsed_ns = start.elapsed().as_nanos();
+
+    let after = snapshot_allocator_stats();
+    result.peak_bytes = after.max_resident - baseline_resident;
+
+    result
+}
+
+fn draw_result_graph(search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
     let root = SVGBackend::new("search_results.svg", (1920, 1000)).into_drawing_area();
     root.fill(&WHITE)?;
+    let panels = root.split_evenly((2, 2));
+
+    draw_comparison_chart(&panels[0], search_results)?;
+    draw_elapsed_chart(&panels[1], search_results)?;
+    draw_peak_bytes_chart(&panels[2], search_results)?;
+    draw_vector_op_chart(&panels[3], search_results)?;
 
-    let mut chart = ChartBuilder::on(&root)
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", "search_results.svg");
+
+    Ok(())
+}
+
+fn draw_comparison_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
+    let mut chart = ChartBuilder::on(area)
         .caption("Search algorithm compplexity", ("sans-serif", (5).percent_height()))
         .set_label_area_size(LabelAreaPosition::Left, (8).percent())
         .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
@@ -64,97 +240,387 @@ fn draw_result_graph(search_results: &SearchResults) -> Result<(), Box<dyn Error
         .y_desc("Comparison count")
         .draw()?;
 
-    //Binary search line
+    //Binary search mean line
     let mut color = Palette99::pick(0).mix(0.9);
     chart
         .draw_series(LineSeries::new(
             search_results.binary_search_results.iter().map(
-                |&SearchResult {
+                |&AggregatedResult {
                         array_length,
-                        comparison_count,
+                        mean_comparison_count,
                         ..
-                    }| (array_length as u32, comparison_count as u32),
+                    }| (array_length as u32, mean_comparison_count as u32),
             ),
             color.stroke_width(3),
         ))?
         .label("Binary search")
         .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
 
-    //Interpolated search line
+    //Interpolated search mean line
     color = Palette99::pick(1).mix(0.9);
     chart
         .draw_series(LineSeries::new(
             search_results.interp_search_results.iter().map(
-                |&SearchResult {
+                |&AggregatedResult {
                         array_length,
-                        comparison_count,
+                        mean_comparison_count,
                         ..
-                    }| (array_length as u32, comparison_count as u32),
+                    }| (array_length as u32, mean_comparison_count as u32),
             ),
             color.stroke_width(3),
         ))?
         .label("Interpolation search")
         .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
 
-    //Interpolated binary search line
+    //Interpolated binary search mean line
     color = Palette99::pick(2).mix(0.9);
     chart
         .draw_series(LineSeries::new(
             search_results.interp_binary_search_results.iter().map(
-                |&SearchResult {
+                |&AggregatedResult {
                         array_length,
-                        comparison_count,
+                        mean_comparison_count,
                         ..
-                    }| (array_length as u32, comparison_count as u32),
+                    }| (array_length as u32, mean_comparison_count as u32),
             ),
             color.stroke_width(3),
         ))?
         .label("Interpolated binary search")
         .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
 
+    //SIMD block search mean line
+    color = Palette99::pick(3).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.simd_block_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_comparison_count,
+                        ..
+                    }| (array_length as u32, mean_comparison_count as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("SIMD block search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //Theoretical log2(n) reference, for binary search's complexity class
+    let binary_color = BLACK.mix(0.6);
+    chart
+        .draw_series(DashedLineSeries::new(
+            (20u32..=500u32).map(|n| (n, (n as f64).log2().round() as u32)),
+            4,
+            2,
+            binary_color.stroke_width(2),
+        ))?
+        .label("log2(n) reference")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], binary_color.filled()));
+
+    //Theoretical log2(log2(n)) reference, for interpolation search's complexity class
+    let interp_color = BLACK.mix(0.3);
+    chart
+        .draw_series(DashedLineSeries::new(
+            (20u32..=500u32).map(|n| (n, (n as f64).log2().log2().max(0.0).round() as u32)),
+            4,
+            2,
+            interp_color.stroke_width(2),
+        ))?
+        .label("log2(log2(n)) reference")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], interp_color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+// Upper edge left-to-right, lower edge right-to-left, so it closes into a polygon.
+fn error_band_points(results: &[AggregatedResult]) -> Vec<(u32, u32)> {
+    let upper = results.iter().map(|r| (r.array_length as u32, r.max_elapsed_ns as u32));
+    let lower = results.iter().rev().map(|r| (r.array_length as u32, r.min_elapsed_ns as u32));
+    upper.chain(lower).collect()
+}
+
+fn draw_elapsed_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
+    let mut chart = ChartBuilder::on(area)
+        .caption("Search algorithm wall-clock time", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (20u32..50_0u32)
+                .log_scale()
+                .with_key_points(vec![10, 50, 100, 150, 200, 250, 400, 500]),
+            (0u32..1_000_000u32)
+                .log_scale(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Array length")
+        .y_desc("Elapsed time (ns)")
+        .draw()?;
+
+    //Binary search mean line with min/max error band
+    let mut color = Palette99::pick(0).mix(0.9);
+    chart.draw_series(std::iter::once(Polygon::new(error_band_points(&search_results.binary_search_results), color.mix(0.15).filled())))?;
+    chart
+        .draw_series(LineSeries::new(
+            search_results.binary_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_elapsed_ns,
+                        ..
+                    }| (array_length as u32, mean_elapsed_ns as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Binary search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //Interpolated search mean line with min/max error band
+    color = Palette99::pick(1).mix(0.9);
+    chart.draw_series(std::iter::once(Polygon::new(error_band_points(&search_results.interp_search_results), color.mix(0.15).filled())))?;
+    chart
+        .draw_series(LineSeries::new(
+            search_results.interp_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_elapsed_ns,
+                        ..
+                    }| (array_length as u32, mean_elapsed_ns as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Interpolation search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //Interpolated binary search mean line with min/max error band
+    color = Palette99::pick(2).mix(0.9);
+    chart.draw_series(std::iter::once(Polygon::new(error_band_points(&search_results.interp_binary_search_results), color.mix(0.15).filled())))?;
+    chart
+        .draw_series(LineSeries::new(
+            search_results.interp_binary_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_elapsed_ns,
+                        ..
+                    }| (array_length as u32, mean_elapsed_ns as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Interpolated binary search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //SIMD block search mean line with min/max error band
+    color = Palette99::pick(3).mix(0.9);
+    chart.draw_series(std::iter::once(Polygon::new(error_band_points(&search_results.simd_block_search_results), color.mix(0.15).filled())))?;
+    chart
+        .draw_series(LineSeries::new(
+            search_results.simd_block_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_elapsed_ns,
+                        ..
+                    }| (array_length as u32, mean_elapsed_ns as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("SIMD block search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+fn draw_peak_bytes_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
+    let mut chart = ChartBuilder::on(area)
+        .caption("Search algorithm peak resident bytes", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (20u32..50_0u32)
+                .log_scale()
+                .with_key_points(vec![10, 50, 100, 150, 200, 250, 400, 500]),
+            (0u32..50_000u32)
+                .log_scale(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Array length")
+        .y_desc("Peak resident bytes")
+        .draw()?;
+
+    //Binary search mean line
+    let mut color = Palette99::pick(0).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.binary_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_peak_bytes,
+                        ..
+                    }| (array_length as u32, mean_peak_bytes.max(0.0) as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Binary search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //Interpolated search mean line
+    color = Palette99::pick(1).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.interp_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_peak_bytes,
+                        ..
+                    }| (array_length as u32, mean_peak_bytes.max(0.0) as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Interpolation search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //Interpolated binary search mean line
+    color = Palette99::pick(2).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.interp_binary_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_peak_bytes,
+                        ..
+                    }| (array_length as u32, mean_peak_bytes.max(0.0) as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Interpolated binary search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    //SIMD block search mean line
+    color = Palette99::pick(3).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.simd_block_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_peak_bytes,
+                        ..
+                    }| (array_length as u32, mean_peak_bytes.max(0.0) as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("SIMD block search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
     chart
         .configure_series_labels()
         .border_style(&BLACK)
         .draw()?;
 
-    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
-    println!("Result has been saved to {}", "search_results.svg");    
-    
     Ok(())
 }
 
-fn write_search_results(search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
-    let mut search_res_writer = csv::Writer::from_path("search_results.csv")?;
+fn draw_vector_op_chart(area: &DrawingArea<SVGBackend, plotters::coord::Shift>, search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
+    let mut chart = ChartBuilder::on(area)
+        .caption("SIMD block search vector-op count", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (20u32..50_0u32)
+                .log_scale()
+                .with_key_points(vec![10, 50, 100, 150, 200, 250, 400, 500]),
+            (0u32..50u32)
+                .log_scale(),
+        )?;
 
-    search_res_writer.write_record(&["Binary", "", "Interpolated", "", "Interpolated binary", ""])?;
-    search_res_writer.write_record(&["Array length", "Comparison count", "Array length", "Comparison count", "Array length", "Comparison count"])?;
+    chart
+        .configure_mesh()
+        .x_desc("Array length")
+        .y_desc("Vector-op count")
+        .draw()?;
 
-    for i in 0..1000 {
-        search_res_writer.write_record(&[search_results.binary_search_results[i].array_length.to_string(), search_results.binary_search_results[i].comparison_count.to_string(),
-                                        search_results.interp_search_results[i].array_length.to_string(), search_results.interp_search_results[i].comparison_count.to_string(),
-                                        search_results.interp_binary_search_results[i].array_length.to_string(), search_results.interp_binary_search_results[i].comparison_count.to_string()])?;
+    //SIMD block search line
+    let color = Palette99::pick(3).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.simd_block_search_results.iter().map(
+                |&AggregatedResult {
+                        array_length,
+                        mean_vector_op_count,
+                        ..
+                    }| (array_length as u32, mean_vector_op_count as u32),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("SIMD block search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+fn write_search_results(config: &BenchmarkConfig, search_results: &SearchResults) -> Result<(), Box<dyn Error>> {
+    // flexible(true): the seed/params row below is narrower than the header/data rows.
+    let mut search_res_writer = csv::WriterBuilder::new().flexible(true).from_path("search_results.csv")?;
+
+    search_res_writer.write_record(&[format!(
+        "# arrays={} min-size={} max-size={} trials={} seed={}",
+        config.array_count, config.min_size, config.max_size, config.trials, config.seed
+    )])?;
+
+    search_res_writer.write_record(&["Binary", "", "", "", "", "", "Interpolated", "", "", "", "", "", "Interpolated binary", "", "", "", "", "", "SIMD block", "", "", "", "", "", ""])?;
+    search_res_writer.write_record(&["Array length", "Mean comparisons", "Mean peak bytes", "Mean elapsed ns", "Min elapsed ns", "Max elapsed ns",
+                                    "Array length", "Mean comparisons", "Mean peak bytes", "Mean elapsed ns", "Min elapsed ns", "Max elapsed ns",
+                                    "Array length", "Mean comparisons", "Mean peak bytes", "Mean elapsed ns", "Min elapsed ns", "Max elapsed ns",
+                                    "Array length", "Mean comparisons", "Mean vector ops", "Mean peak bytes", "Mean elapsed ns", "Min elapsed ns", "Max elapsed ns"])?;
+
+    for i in 0..search_results.binary_search_results.len() {
+        search_res_writer.write_record(&[search_results.binary_search_results[i].array_length.to_string(), search_results.binary_search_results[i].mean_comparison_count.to_string(), search_results.binary_search_results[i].mean_peak_bytes.to_string(), search_results.binary_search_results[i].mean_elapsed_ns.to_string(), search_results.binary_search_results[i].min_elapsed_ns.to_string(), search_results.binary_search_results[i].max_elapsed_ns.to_string(),
+                                        search_results.interp_search_results[i].array_length.to_string(), search_results.interp_search_results[i].mean_comparison_count.to_string(), search_results.interp_search_results[i].mean_peak_bytes.to_string(), search_results.interp_search_results[i].mean_elapsed_ns.to_string(), search_results.interp_search_results[i].min_elapsed_ns.to_string(), search_results.interp_search_results[i].max_elapsed_ns.to_string(),
+                                        search_results.interp_binary_search_results[i].array_length.to_string(), search_results.interp_binary_search_results[i].mean_comparison_count.to_string(), search_results.interp_binary_search_results[i].mean_peak_bytes.to_string(), search_results.interp_binary_search_results[i].mean_elapsed_ns.to_string(), search_results.interp_binary_search_results[i].min_elapsed_ns.to_string(), search_results.interp_binary_search_results[i].max_elapsed_ns.to_string(),
+                                        search_results.simd_block_search_results[i].array_length.to_string(), search_results.simd_block_search_results[i].mean_comparison_count.to_string(), search_results.simd_block_search_results[i].mean_vector_op_count.to_string(), search_results.simd_block_search_results[i].mean_peak_bytes.to_string(), search_results.simd_block_search_results[i].mean_elapsed_ns.to_string(), search_results.simd_block_search_results[i].min_elapsed_ns.to_string(), search_results.simd_block_search_results[i].max_elapsed_ns.to_string()])?;
     }
 
     Ok(())
 }
 
-fn get_num_arrays() -> Vec<Vec<u32>> {
-    let mut num_generator = rand::thread_rng();
+fn get_num_arrays(config: &BenchmarkConfig, rng: &mut StdRng) -> Vec<Vec<u32>> {
+    gen_num_arrays(rng, config.array_count, config.min_size, config.max_size)
+}
+
+// Shared by main's own benchmark and batch_search's, which just picks different bounds.
+pub(crate) fn gen_num_arrays(rng: &mut StdRng, count: usize, min_size: u32, max_size: u32) -> Vec<Vec<u32>> {
     let mut num_arrays: Vec<Vec<u32>> = Vec::new();
-    
-    for _ in 0..1000 {
-        let array_size: u32 = num_generator.gen_range(2..500);
+
+    for _ in 0..count {
+        let array_size: u32 = rng.gen_range(min_size..max_size);
         let mut num_array: Vec<u32> = Vec::with_capacity(array_size as usize);
-        
+
         let mut num_range: u32 = 0;
         for _ in 0..array_size {
-            num_array.push(num_generator.gen_range(num_range..num_range + 10));
+            num_array.push(rng.gen_range(num_range..num_range + 10));
             num_range += 10;
         }
 
         num_arrays.push(num_array);
     }
-    
+
     num_arrays.sort_unstable_by_key(Vec::len);
 
     num_arrays
@@ -162,7 +628,7 @@ fn get_num_arrays() -> Vec<Vec<u32>> {
 
 fn binary_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
     if num_array.len() == 0 {
-        return SearchResult{target_index: -1, comparison_count: 1, array_length: num_array.len()}
+        return SearchResult{target_index: -1, comparison_count: 1, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
     }
 
     let mut start_index: u32 = 0;
@@ -176,24 +642,24 @@ fn binary_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
 
         comparison_count += 1;
         if num_array[search_index as usize] == target_num {
-            return SearchResult{array_length: num_array.len(), target_index: search_index as i32, comparison_count};
+            return SearchResult{array_length: num_array.len(), target_index: search_index as i32, comparison_count, vector_op_count: 0, peak_bytes: 0, elapsed_ns: 0};
         }
-        
+
         comparison_count += 1;
         if num_array[search_index as usize] < target_num {
             start_index = search_index + 1;
-        } else { 
+        } else {
             end_index = search_index - 1;
         }
     }
     comparison_count += 1;
 
-    SearchResult{array_length: num_array.len(), target_index: -1, comparison_count}
+    SearchResult{array_length: num_array.len(), target_index: -1, comparison_count, vector_op_count: 0, peak_bytes: 0, elapsed_ns: 0}
 }
 
 fn interpolation_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
     if num_array.len() == 0 {
-        return SearchResult{target_index: -1, comparison_count: 1, array_length: num_array.len()}
+        return SearchResult{target_index: -1, comparison_count: 1, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
     }
 
     let mut start_index: u32 = 0;
@@ -204,12 +670,12 @@ fn interpolation_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
     while num_array[end_index as usize] != num_array[start_index as usize] && target_num >= num_array[start_index as usize] && target_num <= num_array[end_index as usize] {
         comparison_count += 3;
         interp_index = start_index + (target_num - num_array[start_index as usize]) * (end_index - start_index) / (num_array[end_index as usize] - num_array[start_index as usize]);
- 
+
         comparison_count += 1;
         if target_num == num_array[interp_index as usize] {
-            return SearchResult{target_index: interp_index as i32, comparison_count, array_length: num_array.len()}
+            return SearchResult{target_index: interp_index as i32, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
         }
-        
+
         comparison_count += 1;
         if target_num < num_array[interp_index as usize] {
             end_index = interp_index - 1;
@@ -218,18 +684,18 @@ fn interpolation_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
             start_index = interp_index + 1;
         }
     }
- 
+
     comparison_count += 4;
     if target_num == num_array[start_index as usize] {
-        return SearchResult{target_index: start_index as i32, comparison_count, array_length: num_array.len()}
+        return SearchResult{target_index: start_index as i32, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
     }
-    
-    SearchResult{target_index: -1, comparison_count, array_length: num_array.len()}
+
+    SearchResult{target_index: -1, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
 }
 
 fn interpolated_binary_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
     if num_array.len() == 0 {
-        return SearchResult{target_index: -1, comparison_count: 1, array_length: num_array.len()}
+        return SearchResult{target_index: -1, comparison_count: 1, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
     }
 
     let mut start_index: u32 = 0;
@@ -239,40 +705,129 @@ fn interpolated_binary_search(num_array: &Vec<u32>, target_num: u32) -> SearchRe
     let mut comparison_count: u32 = 1;
 
     while start_index < end_index {
+        comparison_count += 1;
+        // target_num outside [start, end]: bail before the interpolation formula underflows.
+        if target_num < num_array[start_index as usize] || target_num > num_array[end_index as usize] {
+            return SearchResult{target_index: -1, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
+        }
+
         comparison_count += 1;
         inter_index = start_index + (target_num - num_array[start_index as usize]) * (end_index - start_index) / (num_array[end_index as usize] - num_array[start_index as usize]);
-        
+
         comparison_count += 1;
         if target_num > num_array[inter_index as usize] {
             mid_index = (inter_index  + end_index) / 2;
-        
+
             comparison_count += 1;
             if target_num <= num_array[mid_index as usize] {
                 start_index = inter_index  + 1;
-                end_index = mid_index; 
+                end_index = mid_index;
             } else {
                 start_index = mid_index  + 1;
             }
         } else if target_num < num_array[inter_index as usize] {
             mid_index = (inter_index + start_index) / 2;
-            
+
             comparison_count += 1;
             if target_num >= num_array[mid_index as usize] {
                 start_index = mid_index;
-                end_index = inter_index - 1;
+                end_index = inter_index.saturating_sub(1);
             } else {
-                end_index = mid_index - 1;
+                end_index = mid_index.saturating_sub(1);
             }
         } else {
-            return SearchResult{target_index: inter_index as i32, comparison_count, array_length: num_array.len()}
+            return SearchResult{target_index: inter_index as i32, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
         }
         comparison_count += 1;
-    } 
-    
+    }
+
     comparison_count += 2;
     if target_num == num_array[start_index as usize] {
-        return SearchResult{target_index: start_index as i32, comparison_count, array_length: num_array.len()}
+        return SearchResult{target_index: start_index as i32, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
     }
-    
-    return SearchResult{target_index: -1, comparison_count, array_length: num_array.len()}
-}
\ No newline at end of file
+
+    return SearchResult{target_index: -1, comparison_count, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
+}
+
+// Lanes probed per simulated vector compare (stand-in for std::simd, nightly-only).
+const SIMD_LANE_WIDTH: u32 = 8;
+
+fn simd_block_search(num_array: &Vec<u32>, target_num: u32) -> SearchResult {
+    if num_array.len() == 0 {
+        return SearchResult{target_index: -1, comparison_count: 1, vector_op_count: 0, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
+    }
+
+    let mut start_index: u32 = 0;
+    let mut end_index: u32 = num_array.len() as u32 - 1;
+    let mut comparison_count: u32 = 1;
+    let mut vector_op_count: u32 = 0;
+
+    while start_index <= end_index {
+        comparison_count += 2;
+        let interp_index = if num_array[end_index as usize] != num_array[start_index as usize] {
+            start_index + (target_num.saturating_sub(num_array[start_index as usize])) * (end_index - start_index) / (num_array[end_index as usize] - num_array[start_index as usize])
+        } else {
+            start_index
+        };
+
+        let last_possible_start = end_index.saturating_sub(SIMD_LANE_WIDTH - 1).max(start_index);
+        let block_start = interp_index.saturating_sub(SIMD_LANE_WIDTH / 2).clamp(start_index, last_possible_start);
+        let block_end = (block_start + SIMD_LANE_WIDTH - 1).min(end_index);
+
+        vector_op_count += 1;
+        let block = &num_array[block_start as usize..=block_end as usize];
+        if let Some(hit_lane) = block.iter().position(|&candidate| candidate == target_num) {
+            return SearchResult{target_index: (block_start as usize + hit_lane) as i32, comparison_count, vector_op_count, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0};
+        }
+
+        comparison_count += 1;
+        if target_num < block[0] {
+            if block_start == start_index {
+                break;
+            }
+            end_index = block_start - 1;
+        } else if target_num > *block.last().unwrap() {
+            if block_end == end_index {
+                break;
+            }
+            start_index = block_end + 1;
+        } else {
+            break;
+        }
+    }
+
+    SearchResult{target_index: -1, comparison_count, vector_op_count, array_length: num_array.len(), peak_bytes: 0, elapsed_ns: 0}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_search_finds_target() {
+        let num_array = vec![1, 3, 5, 7, 9, 11, 13];
+        assert_eq!(binary_search(&num_array, 7).target_index, 3);
+        assert_eq!(binary_search(&num_array, 4).target_index, -1);
+    }
+
+    #[test]
+    fn interpolation_search_finds_target() {
+        let num_array = vec![1, 3, 5, 7, 9, 11, 13];
+        assert_eq!(interpolation_search(&num_array, 7).target_index, 3);
+        assert_eq!(interpolation_search(&num_array, 4).target_index, -1);
+    }
+
+    #[test]
+    fn interpolated_binary_search_finds_target() {
+        let num_array = vec![1, 3, 5, 7, 9, 11, 13];
+        assert_eq!(interpolated_binary_search(&num_array, 7).target_index, 3);
+        assert_eq!(interpolated_binary_search(&num_array, 4).target_index, -1);
+    }
+
+    #[test]
+    fn simd_block_search_finds_target() {
+        let num_array = vec![1, 3, 5, 7, 9, 11, 13];
+        assert_eq!(simd_block_search(&num_array, 7).target_index, 3);
+        assert_eq!(simd_block_search(&num_array, 4).target_index, -1);
+    }
+}
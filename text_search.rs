@@ -0,0 +1,254 @@
+use csv;
+use rand::distributions::Alphanumeric;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use std::error::Error;
+use plotters::prelude::*;
+
+const MATCH_SCORE: i64 = 4;
+const CONSECUTIVE_BONUS: i64 = 3;
+const WORD_BOUNDARY_BONUS: i64 = 3;
+const GAP_PENALTY: i64 = 1;
+const UNREACHABLE: i64 = i64::MIN / 2;
+
+struct FuzzySearchResult {
+    candidates_scanned: u32,
+    best_score: i64,
+    corpus_size: usize,
+}
+
+struct FuzzySearchResults {
+    results: Vec<FuzzySearchResult>,
+}
+
+pub fn run(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let corpora: Vec<Vec<String>> = get_word_corpora(&mut rng);
+    let mut search_results = FuzzySearchResults{results: Vec::with_capacity(corpora.len())};
+
+    for corpus in corpora {
+        let query = corpus[corpus.len() / 2].clone();
+        search_results.results.push(benchmark_corpus(&corpus, &query));
+    }
+
+    match write_fuzzy_results(seed, &search_results) {
+        Ok(_) => println!("Fuzzy search results successfully written."),
+        Err(e) => println!("Failed to write fuzzy search results:\n{}", e),
+    }
+
+    match draw_fuzzy_result_graph(&search_results) {
+        Ok(_) => println!("Fuzzy search graph successfully drawn"),
+        Err(e) => println!("Failed to draw fuzzy search graph:\n{}", e),
+    }
+}
+
+fn benchmark_corpus(corpus: &[String], query: &str) -> FuzzySearchResult {
+    let mut candidates_scanned: u32 = 0;
+    let mut best_score: i64 = i64::MIN;
+
+    for candidate in corpus {
+        candidates_scanned += 1;
+        let (score, _matched_indices) = fuzzy_score(candidate, query);
+        if score > best_score {
+            best_score = score;
+        }
+    }
+
+    FuzzySearchResult{candidates_scanned, best_score, corpus_size: corpus.len()}
+}
+
+// Smith-Waterman-style alignment: `query` as an ordered subsequence of `candidate`,
+// scoring consecutive matches and word boundaries, penalizing gaps.
+fn fuzzy_score(candidate: &str, query: &str) -> (i64, Vec<usize>) {
+    let cand_chars: Vec<char> = candidate.chars().collect();
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let cand_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let n = cand_chars.len();
+    let m = query_chars.len();
+
+    if m == 0 || n == 0 {
+        return (0, Vec::new());
+    }
+
+    let is_word_boundary = |i: usize| -> bool {
+        i == 0 || !cand_chars[i - 1].is_alphanumeric()
+    };
+
+    // dp[j][i] = best score matching query[..j] with the j-th char at candidate index i - 1.
+    let mut dp = vec![vec![UNREACHABLE; n + 1]; m + 1];
+    let mut back = vec![vec![0usize; n + 1]; m + 1];
+
+    for j in 1..=m {
+        let mut best_before = UNREACHABLE;
+        let mut best_before_index = 0usize;
+
+        for i in 1..=n {
+            if cand_lower[i - 1] == query_chars[j - 1] {
+                let boundary_bonus = if is_word_boundary(i - 1) { WORD_BOUNDARY_BONUS } else { 0 };
+                let base = MATCH_SCORE + boundary_bonus;
+
+                if j == 1 {
+                    dp[j][i] = base;
+                    back[j][i] = 0;
+                } else {
+                    let consecutive_val = if dp[j - 1][i - 1] > UNREACHABLE {
+                        dp[j - 1][i - 1] + CONSECUTIVE_BONUS
+                    } else {
+                        UNREACHABLE
+                    };
+                    let gap_val = if best_before > UNREACHABLE {
+                        best_before - GAP_PENALTY
+                    } else {
+                        UNREACHABLE
+                    };
+
+                    if consecutive_val >= gap_val {
+                        dp[j][i] = base + consecutive_val;
+                        back[j][i] = i - 1;
+                    } else {
+                        dp[j][i] = base + gap_val;
+                        back[j][i] = best_before_index;
+                    }
+                }
+            }
+
+            if dp[j - 1][i - 1] > best_before {
+                best_before = dp[j - 1][i - 1];
+                best_before_index = i - 1;
+            }
+        }
+    }
+
+    let mut best_score = UNREACHABLE;
+    let mut best_end = 0usize;
+    for i in 1..=n {
+        if dp[m][i] > best_score {
+            best_score = dp[m][i];
+            best_end = i;
+        }
+    }
+
+    if best_score <= UNREACHABLE {
+        return (0, Vec::new());
+    }
+
+    let mut matched_indices = Vec::with_capacity(m);
+    let mut i = best_end;
+    for j in (1..=m).rev() {
+        matched_indices.push(i - 1);
+        i = back[j][i];
+    }
+    matched_indices.reverse();
+
+    (best_score, matched_indices)
+}
+
+fn get_word_corpora(rng: &mut StdRng) -> Vec<Vec<String>> {
+    let mut corpora: Vec<Vec<String>> = Vec::new();
+
+    for _ in 0..200 {
+        let corpus_size: u32 = rng.gen_range(2..500);
+        let mut corpus: Vec<String> = Vec::with_capacity(corpus_size as usize);
+
+        for _ in 0..corpus_size {
+            let word_length: u32 = rng.gen_range(3..12);
+            let word: String = (&mut *rng)
+                .sample_iter(&Alphanumeric)
+                .take(word_length as usize)
+                .map(char::from)
+                .collect();
+            corpus.push(word);
+        }
+
+        corpora.push(corpus);
+    }
+
+    corpora.sort_unstable_by_key(Vec::len);
+
+    corpora
+}
+
+fn write_fuzzy_results(seed: u64, search_results: &FuzzySearchResults) -> Result<(), Box<dyn Error>> {
+    let mut fuzzy_res_writer = csv::WriterBuilder::new().flexible(true).from_path("fuzzy_search_results.csv")?;
+
+    fuzzy_res_writer.write_record(&[format!("# corpora=200 seed={}", seed)])?;
+    fuzzy_res_writer.write_record(&["Corpus size", "Candidates scanned", "Best score"])?;
+
+    for result in &search_results.results {
+        fuzzy_res_writer.write_record(&[
+            result.corpus_size.to_string(),
+            result.candidates_scanned.to_string(),
+            result.best_score.to_string(),
+        ])?;
+    }
+
+    Ok(())
+}
+
+fn draw_fuzzy_result_graph(search_results: &FuzzySearchResults) -> Result<(), Box<dyn Error>> {
+    let root = SVGBackend::new("fuzzy_search_results.svg", (1280, 720)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let max_scanned = search_results.results.iter().map(|r| r.candidates_scanned).max().unwrap_or(1);
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Fuzzy text search scan cost", ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(
+            (2u32..500u32).log_scale(),
+            (0u32..max_scanned + 1).log_scale(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Corpus size")
+        .y_desc("Candidates scanned")
+        .draw()?;
+
+    let color = Palette99::pick(4).mix(0.9);
+    chart
+        .draw_series(LineSeries::new(
+            search_results.results.iter().map(
+                |&FuzzySearchResult { corpus_size, candidates_scanned, .. }| (corpus_size as u32, candidates_scanned),
+            ),
+            color.stroke_width(3),
+        ))?
+        .label("Fuzzy text search")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", "fuzzy_search_results.svg");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_scores_higher_than_a_non_match() {
+        let (exact_score, _) = fuzzy_score("hello world", "hello");
+        let (no_match_score, _) = fuzzy_score("hello world", "xyz");
+
+        assert!(exact_score > no_match_score);
+        assert!(exact_score > 0);
+    }
+
+    #[test]
+    fn fuzzy_score_matches_in_order() {
+        let (score, matched_indices) = fuzzy_score("hello world", "hwd");
+
+        assert!(score > 0);
+        assert_eq!(matched_indices, vec![0, 6, 10]);
+    }
+}